@@ -33,12 +33,72 @@ impl Display for Minutes {
     }
 }
 
+impl Minutes {
+    /// Playtime as a `std::time::Duration`.
+    pub fn to_std_duration(self) -> std::time::Duration {
+        std::time::Duration::from_secs(u64::from(self.0) * 60)
+    }
+
+    /// Playtime as a `chrono::Duration`.
+    #[cfg(feature = "chrono")]
+    pub fn to_duration(self) -> chrono::Duration {
+        chrono::Duration::minutes(i64::from(self.0))
+    }
+}
+
 /// Newtype wrapping i64 for Unix Timestamp.
 /// Only used as an indicator rather than a full type.
-/// (I'll probably just replace it with chrono since I'm using it anyway).
+/// Conversions to/from `chrono::DateTime<Utc>` are available behind the
+/// `chrono` feature.
 #[derive(Clone, Copy, Debug, Hash, PartialEq, Eq, PartialOrd, Ord)]
 pub struct UnixTimestamp(pub i64);
 
+/// A [`UnixTimestamp`] couldn't be converted into a `chrono::DateTime<Utc>`
+/// because its epoch seconds fall outside chrono's representable range.
+#[cfg(feature = "chrono")]
+#[derive(Debug, Clone, Copy)]
+pub struct TimestampOutOfRange;
+
+#[cfg(feature = "chrono")]
+impl std::error::Error for TimestampOutOfRange {}
+
+#[cfg(feature = "chrono")]
+impl Display for TimestampOutOfRange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "Unix timestamp is out of chrono's representable range")
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl UnixTimestamp {
+    /// Converts to a `chrono::DateTime<Utc>`, or `None` if the epoch
+    /// seconds fall outside chrono's representable range.
+    pub fn to_datetime(self) -> Option<chrono::DateTime<chrono::Utc>> {
+        chrono::DateTime::from_timestamp(self.0, 0)
+    }
+
+    /// Builds a `UnixTimestamp` from a `chrono::DateTime<Utc>`.
+    pub fn from_datetime(datetime: chrono::DateTime<chrono::Utc>) -> Self {
+        UnixTimestamp(datetime.timestamp())
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl From<chrono::DateTime<chrono::Utc>> for UnixTimestamp {
+    fn from(datetime: chrono::DateTime<chrono::Utc>) -> Self {
+        UnixTimestamp::from_datetime(datetime)
+    }
+}
+
+#[cfg(feature = "chrono")]
+impl TryFrom<UnixTimestamp> for chrono::DateTime<chrono::Utc> {
+    type Error = TimestampOutOfRange;
+
+    fn try_from(timestamp: UnixTimestamp) -> Result<Self, Self::Error> {
+        timestamp.to_datetime().ok_or(TimestampOutOfRange)
+    }
+}
+
 impl<'de> Deserialize<'de> for UnixTimestamp {
     fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
     where
@@ -70,4 +130,38 @@ impl Into<i64> for UnixTimestamp {
     fn into(self) -> i64 {
         self.0
     }
-}
\ No newline at end of file
+}
+
+#[cfg(feature = "chrono")]
+#[cfg(test)]
+mod chrono_tests {
+    use super::*;
+
+    #[test]
+    fn unix_timestamp_round_trips_through_datetime() {
+        let original = UnixTimestamp(1_690_000_000);
+        let datetime = original.to_datetime().expect("in-range timestamp");
+        assert_eq!(UnixTimestamp::from_datetime(datetime), original);
+    }
+
+    #[test]
+    fn from_datetime_matches_into_impl() {
+        let datetime = UnixTimestamp(1_690_000_000)
+            .to_datetime()
+            .expect("in-range timestamp");
+        assert_eq!(UnixTimestamp::from(datetime), UnixTimestamp(1_690_000_000));
+    }
+
+    #[test]
+    fn try_from_fails_outside_chronos_range() {
+        let out_of_range = UnixTimestamp(i64::MAX);
+        assert!(chrono::DateTime::<chrono::Utc>::try_from(out_of_range).is_err());
+    }
+
+    #[test]
+    fn minutes_to_duration_matches_to_std_duration() {
+        let minutes = Minutes(90);
+        assert_eq!(minutes.to_duration(), chrono::Duration::minutes(90));
+        assert_eq!(minutes.to_std_duration(), std::time::Duration::from_secs(90 * 60));
+    }
+}
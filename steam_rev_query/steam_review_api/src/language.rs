@@ -43,6 +43,15 @@ pub enum Language {
     Vietnamese,
 }
 
+/// Text direction for rendering a [`Language`]'s reviews.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum Direction {
+    /// Left-to-right, e.g. English or Japanese.
+    Ltr,
+    /// Right-to-left, e.g. Arabic.
+    Rtl,
+}
+
 #[derive(Debug, Clone, Copy)]
 pub struct LangParseError;
 
@@ -96,6 +105,43 @@ impl Language {
         }
     }
 
+    /// Text direction a UI should use when rendering this language's reviews.
+    pub fn direction(self) -> Direction {
+        use Language::*;
+        match self {
+            All => Direction::Ltr,
+            Arabic => Direction::Rtl,
+            Bulgarian => Direction::Ltr,
+            SimplifiedChinese => Direction::Ltr,
+            TraditionalChinese => Direction::Ltr,
+            Czech => Direction::Ltr,
+            Danish => Direction::Ltr,
+            Dutch => Direction::Ltr,
+            English => Direction::Ltr,
+            Finnish => Direction::Ltr,
+            French => Direction::Ltr,
+            German => Direction::Ltr,
+            Greek => Direction::Ltr,
+            Hungarian => Direction::Ltr,
+            Italian => Direction::Ltr,
+            Japanese => Direction::Ltr,
+            Korean => Direction::Ltr,
+            Norwegian => Direction::Ltr,
+            Polish => Direction::Ltr,
+            Portuguese => Direction::Ltr,
+            PortugueseBrazilian => Direction::Ltr,
+            Romanian => Direction::Ltr,
+            Russian => Direction::Ltr,
+            SpanishSpain => Direction::Ltr,
+            SpanishLatAm => Direction::Ltr,
+            Swedish => Direction::Ltr,
+            Thai => Direction::Ltr,
+            Turkish => Direction::Ltr,
+            Ukrainian => Direction::Ltr,
+            Vietnamese => Direction::Ltr,
+        }
+    }
+
     /// Shorthand language code as represented by the Steam web API.
     pub fn language_code(self) -> &'static str {
         use Language::*;
@@ -112,7 +158,7 @@ impl Language {
             Finnish => "fi",
             French => "fr",
             German => "de",
-            Greek => "el el",
+            Greek => "el",
             Hungarian => "hu",
             Italian => "it",
             Japanese => "ja",
@@ -129,7 +175,7 @@ impl Language {
             Thai => "th",
             Turkish => "tr",
             Ukrainian => "uk",
-            Vietnamese => "vn",
+            Vietnamese => "vi",
         }
     }
 
@@ -169,6 +215,201 @@ impl Language {
             Vietnamese => "Ti???ng Vi???t",
         }
     }
+
+    /// ISO 639-1 (two-letter) code, when one exists. `Language::All` isn't
+    /// a real language, so it has none.
+    pub fn iso639_1(self) -> Option<&'static str> {
+        use Language::*;
+        match self {
+            All => None,
+            Arabic => Some("ar"),
+            Bulgarian => Some("bg"),
+            SimplifiedChinese | TraditionalChinese => Some("zh"),
+            Czech => Some("cs"),
+            Danish => Some("da"),
+            Dutch => Some("nl"),
+            English => Some("en"),
+            Finnish => Some("fi"),
+            French => Some("fr"),
+            German => Some("de"),
+            Greek => Some("el"),
+            Hungarian => Some("hu"),
+            Italian => Some("it"),
+            Japanese => Some("ja"),
+            Korean => Some("ko"),
+            Norwegian => Some("no"),
+            Polish => Some("pl"),
+            Portuguese | PortugueseBrazilian => Some("pt"),
+            Romanian => Some("ro"),
+            Russian => Some("ru"),
+            SpanishSpain | SpanishLatAm => Some("es"),
+            Swedish => Some("sv"),
+            Thai => Some("th"),
+            Turkish => Some("tr"),
+            Ukrainian => Some("uk"),
+            Vietnamese => Some("vi"),
+        }
+    }
+
+    /// ISO 639-3 (three-letter) code. `Language::All` maps to `"und"`
+    /// (undetermined), the ISO 639-3 catch-all, since it isn't a real
+    /// language.
+    pub fn iso639_3(self) -> &'static str {
+        use Language::*;
+        match self {
+            All => "und",
+            Arabic => "ara",
+            Bulgarian => "bul",
+            SimplifiedChinese | TraditionalChinese => "zho",
+            Czech => "ces",
+            Danish => "dan",
+            Dutch => "nld",
+            English => "eng",
+            Finnish => "fin",
+            French => "fra",
+            German => "deu",
+            Greek => "ell",
+            Hungarian => "hun",
+            Italian => "ita",
+            Japanese => "jpn",
+            Korean => "kor",
+            Norwegian => "nor",
+            Polish => "pol",
+            Portuguese | PortugueseBrazilian => "por",
+            Romanian => "ron",
+            Russian => "rus",
+            SpanishSpain | SpanishLatAm => "spa",
+            Swedish => "swe",
+            Thai => "tha",
+            Turkish => "tur",
+            Ukrainian => "ukr",
+            Vietnamese => "vie",
+        }
+    }
+
+    /// Language's English display name, as opposed to [`Language::native_name`].
+    pub fn english_name(self) -> &'static str {
+        use Language::*;
+        match self {
+            All => "All",
+            Arabic => "Arabic",
+            Bulgarian => "Bulgarian",
+            SimplifiedChinese => "Simplified Chinese",
+            TraditionalChinese => "Traditional Chinese",
+            Czech => "Czech",
+            Danish => "Danish",
+            Dutch => "Dutch",
+            English => "English",
+            Finnish => "Finnish",
+            French => "French",
+            German => "German",
+            Greek => "Greek",
+            Hungarian => "Hungarian",
+            Italian => "Italian",
+            Japanese => "Japanese",
+            Korean => "Korean",
+            Norwegian => "Norwegian",
+            Polish => "Polish",
+            Portuguese => "Portuguese",
+            PortugueseBrazilian => "Portuguese (Brazil)",
+            Romanian => "Romanian",
+            Russian => "Russian",
+            SpanishSpain => "Spanish",
+            SpanishLatAm => "Spanish (Latin America)",
+            Swedish => "Swedish",
+            Thai => "Thai",
+            Turkish => "Turkish",
+            Ukrainian => "Ukrainian",
+            Vietnamese => "Vietnamese",
+        }
+    }
+
+    /// Splits this language into its BCP47 primary language subtag and,
+    /// when the variant bundles one, a region/script subtag
+    /// (`PortugueseBrazilian` → `("pt", Some("BR"))`, `Portuguese` →
+    /// `("pt", None)`).
+    pub fn subtags(self) -> (&'static str, Option<&'static str>) {
+        use Language::*;
+        match self {
+            All => ("all", None),
+            SimplifiedChinese => ("zh", Some("CN")),
+            TraditionalChinese => ("zh", Some("TW")),
+            PortugueseBrazilian => ("pt", Some("BR")),
+            SpanishLatAm => ("es", Some("419")),
+            other => (
+                other
+                    .iso639_1()
+                    .expect("every Language other than All has an ISO 639-1 code"),
+                None,
+            ),
+        }
+    }
+
+    /// Canonical BCP47 tag for this language, e.g. `pt-BR` for
+    /// [`Language::PortugueseBrazilian`] or `en` for [`Language::English`].
+    pub fn to_language_identifier(self) -> String {
+        match self.subtags() {
+            (primary, Some(region)) => format!("{primary}-{region}"),
+            (primary, None) => primary.to_owned(),
+        }
+    }
+
+    /// Recovers a [`Language`] from a parsed BCP47
+    /// [`unic_langid::LanguageIdentifier`], matching on the language subtag
+    /// and, when present, the region subtag to disambiguate dialects (e.g.
+    /// `pt` + `BR` → [`Language::PortugueseBrazilian`], bare `pt` →
+    /// [`Language::Portuguese`]).
+    #[cfg(feature = "unic-langid")]
+    pub fn from_language_identifier(id: &unic_langid::LanguageIdentifier) -> Option<Language> {
+        use Language::*;
+
+        let language = id.language.as_str();
+        let region = id.region.as_ref().map(|region| region.as_str());
+
+        match (language, region) {
+            ("all", _) => Some(All),
+            ("zh", Some("TW")) => Some(TraditionalChinese),
+            ("zh", _) => Some(SimplifiedChinese),
+            ("pt", Some("BR")) => Some(PortugueseBrazilian),
+            ("pt", _) => Some(Portuguese),
+            ("es", Some("419")) => Some(SpanishLatAm),
+            ("es", _) => Some(SpanishSpain),
+            ("ar", _) => Some(Arabic),
+            ("bg", _) => Some(Bulgarian),
+            ("cs", _) => Some(Czech),
+            ("da", _) => Some(Danish),
+            ("nl", _) => Some(Dutch),
+            ("en", _) => Some(English),
+            ("fi", _) => Some(Finnish),
+            ("fr", _) => Some(French),
+            ("de", _) => Some(German),
+            ("el", _) => Some(Greek),
+            ("hu", _) => Some(Hungarian),
+            ("it", _) => Some(Italian),
+            ("ja", _) => Some(Japanese),
+            ("ko", _) => Some(Korean),
+            ("no", _) => Some(Norwegian),
+            ("pl", _) => Some(Polish),
+            ("ro", _) => Some(Romanian),
+            ("ru", _) => Some(Russian),
+            ("sv", _) => Some(Swedish),
+            ("th", _) => Some(Thai),
+            ("tr", _) => Some(Turkish),
+            ("uk", _) => Some(Ukrainian),
+            ("vi", _) => Some(Vietnamese),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(feature = "unic-langid")]
+impl From<Language> for unic_langid::LanguageIdentifier {
+    /// Converts a [`Language`] into its canonical BCP47 [`unic_langid::LanguageIdentifier`].
+    fn from(lang: Language) -> Self {
+        lang.to_language_identifier()
+            .parse()
+            .expect("Language::to_language_identifier always produces a well-formed BCP47 tag")
+    }
 }
 
 impl Display for Language {
@@ -177,11 +418,136 @@ impl Display for Language {
     }
 }
 
+/// Selects which RFC 4647 matching scheme [`Language::negotiate_with`] applies
+/// when comparing an `Accept-Language` header against a supported set.
+#[derive(Debug, Clone, Copy, Hash, PartialEq, Eq)]
+pub enum NegotiationStrategy {
+    /// Basic filtering: keep every supported language that matches any
+    /// requested range, ranked by the range's priority.
+    Filtering,
+    /// Lookup: return only the single best supported language.
+    Lookup,
+}
+
+/// Splits an `Accept-Language` header into `(range, q)` pairs, dropping
+/// `q=0` entries and stable-sorting by descending weight. `q` defaults to
+/// `1.0` when absent, per RFC 4647.
+fn parse_accept_language(accept_language: &str) -> Vec<(&str, f32)> {
+    let mut ranges: Vec<(&str, f32)> = accept_language
+        .split(',')
+        .filter_map(|range| {
+            let range = range.trim();
+            if range.is_empty() {
+                return None;
+            }
+
+            let mut parts = range.split(';');
+            let tag = parts.next().unwrap().trim();
+            let q = parts
+                .find_map(|param| param.trim().strip_prefix("q="))
+                .and_then(|q| q.trim().parse::<f32>().ok())
+                .unwrap_or(1.0);
+
+            (q > 0.0).then_some((tag, q))
+        })
+        .collect();
+
+    ranges.sort_by(|(_, a), (_, b)| b.partial_cmp(a).unwrap_or(std::cmp::Ordering::Equal));
+    ranges
+}
+
+/// Finds the single best language in `supported` for `range` per the RFC
+/// 4647 lookup algorithm: try an exact [`Language::language_code`] match,
+/// then progressively truncate `range` at its last hyphen and retry until
+/// a match is found or the range is exhausted.
+fn lookup_match(range: &str, supported: &[Language]) -> Option<Language> {
+    let mut candidate = range;
+    loop {
+        if let Some(lang) = supported
+            .iter()
+            .find(|lang| lang.language_code().eq_ignore_ascii_case(candidate))
+        {
+            return Some(*lang);
+        }
+
+        match candidate.rfind('-') {
+            Some(idx) => candidate = &candidate[..idx],
+            None => return None,
+        }
+    }
+}
+
+/// RFC 4647 basic filtering predicate: `range` matches `tag` if they're
+/// equal case-insensitively, or if `range` is a prefix of `tag` that ends
+/// exactly on a subtag boundary (`es` matches `es-419`, but not `est`).
+fn range_is_prefix_of_tag(range: &str, tag: &str) -> bool {
+    if range.eq_ignore_ascii_case(tag) {
+        return true;
+    }
+
+    tag.len() > range.len()
+        && tag[..range.len()].eq_ignore_ascii_case(range)
+        && tag.as_bytes()[range.len()] == b'-'
+}
+
+impl Language {
+    /// Negotiates a single best language from a client's `Accept-Language`
+    /// header using RFC 4647 lookup, falling back to `default` if nothing
+    /// in `supported` matches.
+    pub fn negotiate(accept_language: &str, supported: &[Language], default: Language) -> Language {
+        // `Lookup` always returns a single element that already folds in
+        // `default`, so there's no further fallback to apply here.
+        Self::negotiate_with(accept_language, supported, default, NegotiationStrategy::Lookup)[0]
+    }
+
+    /// Negotiates every supported language that satisfies a client's
+    /// `Accept-Language` header using RFC 4647 basic filtering, ranked by
+    /// the matching range's priority.
+    pub fn negotiate_all(accept_language: &str, supported: &[Language]) -> Vec<Language> {
+        Self::negotiate_with(accept_language, supported, Language::All, NegotiationStrategy::Filtering)
+    }
+
+    /// Negotiates `supported` languages against `accept_language` per
+    /// `strategy`. [`NegotiationStrategy::Lookup`] returns at most one
+    /// language (or `default` if nothing matches); [`NegotiationStrategy::Filtering`]
+    /// returns every matching language ranked by range priority.
+    pub fn negotiate_with(
+        accept_language: &str,
+        supported: &[Language],
+        default: Language,
+        strategy: NegotiationStrategy,
+    ) -> Vec<Language> {
+        let ranges = parse_accept_language(accept_language);
+
+        match strategy {
+            NegotiationStrategy::Lookup => {
+                let best = ranges
+                    .iter()
+                    .find_map(|(range, _q)| lookup_match(range, supported))
+                    .unwrap_or(default);
+                vec![best]
+            }
+            NegotiationStrategy::Filtering => {
+                let mut matched = Vec::new();
+                for (range, _q) in ranges {
+                    for &lang in supported {
+                        if range_is_prefix_of_tag(range, lang.language_code()) && !matched.contains(&lang) {
+                            matched.push(lang);
+                        }
+                    }
+                }
+                matched
+            }
+        }
+    }
+}
+
 impl FromStr for Language {
     type Err = LangParseError;
 
     /// String slice to Language.
-    /// Native names as well as shorthands are handled.
+    /// Native names, Steam shorthands, ISO 639-1/639-3 codes, and English
+    /// names are all handled, matched case-insensitively.
     ///
     /// ## Errors
     /// Returns [LangParseError] if an unsupported language is passed in.
@@ -189,37 +555,37 @@ impl FromStr for Language {
     /// languages...in which case you should let me know!
     fn from_str(s: &str) -> Result<Self, LangParseError> {
         use Language::*;
-        match s {
-            "all" => Ok(All),
-            "arabic" | "??????????????" | "ar" => Ok(Arabic),
-            "bulgarian" | "?????????????????? ????????" | "bg" => Ok(Bulgarian),
-            "schinese" | "????????????" | "zh-CN" => Ok(SimplifiedChinese),
-            "tchinese" | "????????????" | "zh-TW" => Ok(TraditionalChinese),
-            "czech" | "??e??tina" | "cs" => Ok(Czech),
-            "danish" | "Dansk" | "da" => Ok(Danish),
-            "dutch" | "Nederlands" | "nl" => Ok(Dutch),
-            "english" | "English" | "en" => Ok(English),
-            "finnish" | "Suomi" | "fl" => Ok(Finnish),
-            "french" | "Fran??ais" | "fr" => Ok(French),
-            "german" | "Deutsch" | "de" => Ok(German),
-            "greek" | "????????????????" | "el" => Ok(Greek),
-            "hungarian" | "Magyar" | "hu" => Ok(Hungarian),
-            "italian" | "Italiano" | "it" => Ok(Italian),
-            "japanese" | "?????????" | "ja" => Ok(Japanese),
-            "koreana" | "?????????" | "ko" => Ok(Korean),
-            "norwegian" | "Norsk" | "no" => Ok(Norwegian),
-            "polish" | "Polski" | "pl" => Ok(Polish),
-            "portuguese" | "Portugu??s" | "pt" => Ok(Portuguese),
-            "brazilian" | "Portugu??s-Brasil" | "pt-BR" => Ok(PortugueseBrazilian),
-            "romanian" | "Rom??n??" | "ro" => Ok(Romanian),
-            "russian" | "??????????????" | "ru" => Ok(Russian),
-            "spanish" | "Espa??ol-Espa??a" | "es" => Ok(SpanishSpain),
-            "latam" | "Espa??ol-Latinoam??rica" | "es-419" => Ok(SpanishLatAm),
-            "swedish" | "Svenska" | "sv" => Ok(Swedish),
-            "thai" | "?????????" | "th" => Ok(Thai),
-            "turkish" | "T??rk??e" | "tr" => Ok(Turkish),
-            "ukrainian" | "????????????????????" | "uk" => Ok(Ukrainian),
-            "vietnamese" | "Ti???ng Vi???t" | "vn" => Ok(Vietnamese),
+        match s.to_lowercase().as_str() {
+            "all" | "und" => Ok(All),
+            "arabic" | "??????????????" | "ar" | "ara" => Ok(Arabic),
+            "bulgarian" | "?????????????????? ????????" | "bg" | "bul" => Ok(Bulgarian),
+            "schinese" | "????????????" | "zh-cn" | "zh" | "zho" | "simplified chinese" => Ok(SimplifiedChinese),
+            "tchinese" | "????????????" | "zh-tw" | "traditional chinese" => Ok(TraditionalChinese),
+            "czech" | "??e??tina" | "cs" | "ces" => Ok(Czech),
+            "danish" | "dansk" | "da" | "dan" => Ok(Danish),
+            "dutch" | "nederlands" | "nl" | "nld" => Ok(Dutch),
+            "english" | "en" | "eng" => Ok(English),
+            "finnish" | "suomi" | "fl" | "fi" | "fin" => Ok(Finnish),
+            "french" | "fran??ais" | "fr" | "fra" => Ok(French),
+            "german" | "deutsch" | "de" | "deu" => Ok(German),
+            "greek" | "????????????????" | "el" | "ell" => Ok(Greek),
+            "hungarian" | "magyar" | "hu" | "hun" => Ok(Hungarian),
+            "italian" | "italiano" | "it" | "ita" => Ok(Italian),
+            "japanese" | "?????????" | "ja" | "jpn" => Ok(Japanese),
+            "koreana" | "?????????" | "ko" | "kor" | "korean" => Ok(Korean),
+            "norwegian" | "norsk" | "no" | "nor" => Ok(Norwegian),
+            "polish" | "polski" | "pl" | "pol" => Ok(Polish),
+            "portuguese" | "portugu??s" | "pt" | "por" => Ok(Portuguese),
+            "brazilian" | "portugu??s-brasil" | "pt-br" | "portuguese (brazil)" => Ok(PortugueseBrazilian),
+            "romanian" | "rom??n??" | "ro" | "ron" => Ok(Romanian),
+            "russian" | "??????????????" | "ru" | "rus" => Ok(Russian),
+            "spanish" | "espa??ol-espa??a" | "es" | "spa" => Ok(SpanishSpain),
+            "latam" | "espa??ol-latinoam??rica" | "es-419" | "spanish (latin america)" => Ok(SpanishLatAm),
+            "swedish" | "svenska" | "sv" | "swe" => Ok(Swedish),
+            "thai" | "?????????" | "th" | "tha" => Ok(Thai),
+            "turkish" | "t??rk??e" | "tr" | "tur" => Ok(Turkish),
+            "ukrainian" | "????????????????????" | "uk" | "ukr" => Ok(Ukrainian),
+            "vietnamese" | "ti???ng vi???t" | "vn" | "vi" | "vie" => Ok(Vietnamese),
             _ => Err(LangParseError),
         }
     }
@@ -268,3 +634,193 @@ mod tests {
         let _err = Language::deserialize(cat_lang).unwrap_err();
     }
 }
+
+#[cfg(test)]
+mod iso639_tests {
+    use super::*;
+
+    #[test]
+    fn iso639_1_shares_a_code_across_dialects() {
+        assert_eq!(Language::SimplifiedChinese.iso639_1(), Some("zh"));
+        assert_eq!(Language::TraditionalChinese.iso639_1(), Some("zh"));
+        assert_eq!(Language::All.iso639_1(), None);
+    }
+
+    #[test]
+    fn iso639_3_is_always_present() {
+        assert_eq!(Language::Korean.iso639_3(), "kor");
+        assert_eq!(Language::All.iso639_3(), "und");
+    }
+
+    #[test]
+    fn english_name_differs_from_native_name() {
+        assert_eq!(Language::SimplifiedChinese.english_name(), "Simplified Chinese");
+        assert_eq!(Language::PortugueseBrazilian.english_name(), "Portuguese (Brazil)");
+    }
+
+    #[test]
+    fn from_str_accepts_iso_codes_case_insensitively() {
+        assert_eq!("KO".parse::<Language>().unwrap(), Language::Korean);
+        assert_eq!("zh".parse::<Language>().unwrap(), Language::SimplifiedChinese);
+        assert_eq!("Pt".parse::<Language>().unwrap(), Language::Portuguese);
+    }
+
+    #[test]
+    fn from_str_accepts_english_names() {
+        assert_eq!("Korean".parse::<Language>().unwrap(), Language::Korean);
+        assert_eq!(
+            "simplified chinese".parse::<Language>().unwrap(),
+            Language::SimplifiedChinese
+        );
+    }
+
+    #[test]
+    fn from_str_is_case_insensitive_for_shorthands() {
+        assert_eq!("EN".parse::<Language>().unwrap(), Language::English);
+        assert_eq!("En".parse::<Language>().unwrap(), Language::English);
+        assert_eq!("en".parse::<Language>().unwrap(), Language::English);
+    }
+}
+
+#[cfg(test)]
+mod direction_tests {
+    use super::*;
+
+    #[test]
+    fn arabic_is_rtl() {
+        assert_eq!(Language::Arabic.direction(), Direction::Rtl);
+    }
+
+    #[test]
+    fn everything_else_is_ltr() {
+        for lang in [
+            Language::All,
+            Language::English,
+            Language::Japanese,
+            Language::Vietnamese,
+        ] {
+            assert_eq!(lang.direction(), Direction::Ltr);
+        }
+    }
+}
+
+#[cfg(test)]
+mod subtag_tests {
+    use super::*;
+
+    #[test]
+    fn splits_bundled_region() {
+        assert_eq!(Language::PortugueseBrazilian.subtags(), ("pt", Some("BR")));
+        assert_eq!(Language::SpanishLatAm.subtags(), ("es", Some("419")));
+        assert_eq!(Language::SimplifiedChinese.subtags(), ("zh", Some("CN")));
+    }
+
+    #[test]
+    fn bare_primary_has_no_region() {
+        assert_eq!(Language::Portuguese.subtags(), ("pt", None));
+        assert_eq!(Language::English.subtags(), ("en", None));
+    }
+
+    #[test]
+    fn to_language_identifier_is_canonical_bcp47() {
+        assert_eq!(Language::PortugueseBrazilian.to_language_identifier(), "pt-BR");
+        assert_eq!(Language::English.to_language_identifier(), "en");
+        assert_eq!(Language::SpanishLatAm.to_language_identifier(), "es-419");
+    }
+
+    #[test]
+    fn language_codes_are_well_formed() {
+        // Regression: these were malformed ("el el", "vn") before canonical
+        // tag output required them to be well-formed BCP47 primary subtags.
+        assert_eq!(Language::Greek.language_code(), "el");
+        assert_eq!(Language::Vietnamese.language_code(), "vi");
+    }
+}
+
+#[cfg(feature = "unic-langid")]
+#[cfg(test)]
+mod unic_langid_tests {
+    use super::*;
+
+    #[test]
+    fn round_trips_a_dialect_with_region() {
+        let id: unic_langid::LanguageIdentifier = Language::PortugueseBrazilian.into();
+        assert_eq!(id.region.as_ref().map(|region| region.as_str()), Some("BR"));
+        assert_eq!(
+            Language::from_language_identifier(&id),
+            Some(Language::PortugueseBrazilian)
+        );
+    }
+
+    #[test]
+    fn round_trips_a_bare_primary_subtag() {
+        let id: unic_langid::LanguageIdentifier = Language::English.into();
+        assert_eq!(Language::from_language_identifier(&id), Some(Language::English));
+    }
+
+    #[test]
+    fn round_trips_all() {
+        let id: unic_langid::LanguageIdentifier = Language::All.into();
+        assert_eq!(Language::from_language_identifier(&id), Some(Language::All));
+    }
+}
+
+#[cfg(test)]
+mod negotiation_tests {
+    use super::*;
+
+    const SUPPORTED: &[Language] = &[
+        Language::English,
+        Language::Portuguese,
+        Language::PortugueseBrazilian,
+        Language::SpanishSpain,
+        Language::SpanishLatAm,
+    ];
+
+    #[test]
+    fn lookup_exact_match() {
+        let lang = Language::negotiate("pt-BR,en;q=0.5", SUPPORTED, Language::English);
+        assert_eq!(lang, Language::PortugueseBrazilian);
+    }
+
+    #[test]
+    fn lookup_falls_back_to_primary_subtag() {
+        // es-419 isn't supported here, but es is, so truncation should find it.
+        let lang = Language::negotiate("es-419,en;q=0.5", &[Language::English, Language::SpanishSpain], Language::English);
+        assert_eq!(lang, Language::SpanishSpain);
+    }
+
+    #[test]
+    fn lookup_uses_default_when_nothing_matches() {
+        let lang = Language::negotiate("ja,ko", SUPPORTED, Language::English);
+        assert_eq!(lang, Language::English);
+    }
+
+    #[test]
+    fn filtering_ranks_by_q_and_keeps_all_matches() {
+        let langs = Language::negotiate_all("pt;q=0.5,es;q=0.9", SUPPORTED);
+        assert_eq!(
+            langs,
+            vec![
+                Language::SpanishSpain,
+                Language::SpanishLatAm,
+                Language::Portuguese,
+                Language::PortugueseBrazilian
+            ]
+        );
+    }
+
+    #[test]
+    fn filtering_drops_q_zero() {
+        let langs = Language::negotiate_all("en;q=0", SUPPORTED);
+        assert!(langs.is_empty());
+    }
+
+    #[test]
+    fn q_is_found_even_with_extra_params_after_it() {
+        // "en;q=0.8;foo=bar" must read q=0.8, not silently fall back to 1.0
+        // and tie with (or outrank) a genuinely higher-priority range.
+        let langs = Language::negotiate_all("en;q=0.8;foo=bar,pt;q=1.0", SUPPORTED);
+        assert_eq!(langs, vec![Language::Portuguese, Language::PortugueseBrazilian, Language::English]);
+    }
+}